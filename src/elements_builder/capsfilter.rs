@@ -1,5 +1,4 @@
 use anyhow::Result;
-use gst::caps::{Builder, NoFeature};
 use gst::prelude::*;
 use gst::{Caps, Element, ElementFactory};
 
@@ -10,7 +9,7 @@ use gst::{Caps, Element, ElementFactory};
 #[derive(Debug)]
 pub struct CapsFilterBuilder {
     element: Element,
-    caps: Builder<NoFeature>,
+    caps: Caps,
 }
 
 impl CapsFilterBuilder {
@@ -24,8 +23,8 @@ impl CapsFilterBuilder {
         let element = ElementFactory::make_with_name("capsfilter", Some("capsfilter"))
             .expect("Failed to create capsfilter element");
 
-        // Initialize the caps builder with the specified media type
-        let caps = Caps::builder(media_type);
+        // Initialize the caps with the specified media type
+        let caps = Caps::builder(media_type).build();
 
         Self { element, caps }
     }
@@ -36,7 +35,7 @@ impl CapsFilterBuilder {
     ///
     /// * `width`: The width of the video in pixels.
     pub fn with_width(mut self, width: i32) -> Self {
-        self.caps = self.caps.field("width", width);
+        self.caps.make_mut().set("width", width);
         self
     }
 
@@ -46,7 +45,7 @@ impl CapsFilterBuilder {
     ///
     /// * `height`: The height of the video in pixels.
     pub fn with_height(mut self, height: i32) -> Self {
-        self.caps = self.caps.field("height", height);
+        self.caps.make_mut().set("height", height);
         self
     }
 
@@ -56,7 +55,7 @@ impl CapsFilterBuilder {
     ///
     /// * `framerate`: The framerate of the video as a tuple of (numerator, denominator).
     pub fn with_framerate(mut self, framerate: (i32, i32)) -> Self {
-        self.caps = self.caps.field("framerate", gst::Fraction::new(framerate.0, framerate.1));
+        self.caps.make_mut().set("framerate", gst::Fraction::new(framerate.0, framerate.1));
         self
     }
 
@@ -66,7 +65,7 @@ impl CapsFilterBuilder {
     ///
     /// * `profile`: The profile to use for the video (e.g., "high", "baseline").
     pub fn with_profile(mut self, profile: &str) -> Self {
-        self.caps = self.caps.field("profile", profile);
+        self.caps.make_mut().set("profile", profile);
         self
     }
 
@@ -76,10 +75,15 @@ impl CapsFilterBuilder {
     ///
     /// * `format`: The pixel format of the video (e.g., "I420", "NV12").
     pub fn with_format(mut self, format: &str) -> Self {
-        self.caps = self.caps.field("format", format);
+        self.caps.make_mut().set("format", format);
         self
     }
 
+    /// Returns the `caps` configured so far, before they are applied to the element.
+    pub fn caps(&self) -> &Caps {
+        &self.caps
+    }
+
     /// Builds and returns the configured `capsfilter` instance.
     ///
     /// The `caps` property of the element is set based on the configured values.
@@ -87,10 +91,9 @@ impl CapsFilterBuilder {
     /// # Returns
     ///
     /// A `Result` containing the built `gst::Element` instance or an error if the element could not be created.
-    pub fn build(mut self) -> Result<Element> {
-        // Build the caps and set it to the capsfilter element
-        let caps = self.caps.build();
-        self.element.set_property("caps", &caps);
+    pub fn build(self) -> Result<Element> {
+        // Set the configured caps to the capsfilter element
+        self.element.set_property("caps", &self.caps);
 
         Ok(self.element)
     }
@@ -3,6 +3,39 @@ use gst::prelude::*;
 use gst::ElementFactory;
 use gst_hlssink3::hlssink3::HlsSink3PlaylistType;
 
+/// Minimum number of segments a live playlist should expose so players can stay on the live edge.
+const MIN_LIVE_PLAYLIST_LENGTH: u32 = 3;
+
+/// Computes the `playlist-length` needed to cover a DVR window of `dvr_seconds`.
+///
+/// # Arguments
+///
+/// * `target_duration`: The target duration of each HLS segment in seconds.
+/// * `dvr_seconds`: The desired DVR window in seconds.
+///
+/// The window is rounded up to whole segments. Windows shorter than three segments are
+/// rejected, since shorter live playlists make players fall off the live edge.
+pub fn playlist_length_for_dvr(target_duration: u32, dvr_seconds: u32) -> Result<u32> {
+    if target_duration == 0 {
+        return Err(anyhow::anyhow!(
+            "Cannot compute playlist length for a DVR window with a target duration of 0"
+        ));
+    }
+
+    let length = dvr_seconds.div_ceil(target_duration);
+    if length < MIN_LIVE_PLAYLIST_LENGTH {
+        return Err(anyhow::anyhow!(
+            "DVR window of {}s is too short: at least {}s ({} segments of {}s) are required",
+            dvr_seconds,
+            MIN_LIVE_PLAYLIST_LENGTH.saturating_mul(target_duration),
+            MIN_LIVE_PLAYLIST_LENGTH,
+            target_duration
+        ));
+    }
+
+    Ok(length)
+}
+
 /// A builder for configuring and creating the `hlssink3` GStreamer element.
 ///
 /// The builder provides an interface for setting properties like `playlist-location`,
@@ -11,6 +44,8 @@ use gst_hlssink3::hlssink3::HlsSink3PlaylistType;
 #[derive(Debug, Clone)]
 pub struct HlsSink3Builder {
     element: gst::Element,
+    // DVR window in seconds, resolved into `playlist-length` when building.
+    dvr_seconds: Option<u32>,
 }
 
 impl HlsSink3Builder {
@@ -31,7 +66,20 @@ impl HlsSink3Builder {
         element.set_property("location", format!("{}/segment_%02d.ts", location));
         element.set_property("playlist-location", format!("{}/playlist.m3u8", playlist_location));
 
-        Self { element }
+        Self {
+            element,
+            dvr_seconds: None,
+        }
+    }
+
+    /// Returns the underlying `hlssink3` element without consuming the builder.
+    pub fn element(&self) -> &gst::Element {
+        &self.element
+    }
+
+    /// Returns the DVR window in seconds, if one was configured with `with_dvr_seconds`.
+    pub fn dvr_seconds(&self) -> Option<u32> {
+        self.dvr_seconds
     }
 
     /// Sets the `playlist-location` property of the `hlssink3` element.
@@ -81,6 +129,21 @@ impl HlsSink3Builder {
         self
     }
 
+    /// Sets the `playlist-length` property from a DVR window expressed in seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `dvr_seconds`: The desired DVR window in seconds.
+    ///
+    /// The length is derived from the final `target-duration` when the element is built,
+    /// overriding any value set with `with_playlist_length`. The encoder's keyframe interval
+    /// is not taken into account: segments only split on keyframes, so a GOP longer than
+    /// `target-duration` yields a DVR window longer than requested.
+    pub fn with_dvr_seconds(mut self, dvr_seconds: u32) -> Self {
+        self.dvr_seconds = Some(dvr_seconds);
+        self
+    }
+
     /// Sets the `max-files` property of the `hlssink3` element.
     ///
     /// # Arguments
@@ -110,12 +173,46 @@ impl HlsSink3Builder {
         }
     }
 
+    /// Returns the `playlist-length` the element will be built with.
+    ///
+    /// When a DVR window is configured, the length is derived from it and the current
+    /// `target-duration`, and checked against a non-zero `max-files`, which must keep at least
+    /// one segment more than the playlist lists.
+    pub fn resolved_playlist_length(&self) -> Result<u32> {
+        let dvr_seconds = match self.dvr_seconds {
+            Some(dvr_seconds) => dvr_seconds,
+            None => return Ok(self.element.property::<u32>("playlist-length")),
+        };
+
+        let target_duration = self.element.property::<u32>("target-duration");
+        let length = playlist_length_for_dvr(target_duration, dvr_seconds)?;
+
+        let max_files = self.element.property::<u32>("max-files");
+        if max_files != 0 && max_files <= length {
+            return Err(anyhow::anyhow!(
+                "max-files={} would delete segments still listed by playlist-length={} \
+                 (DVR window of {}s); max-files must be at least {}",
+                max_files,
+                length,
+                dvr_seconds,
+                length.saturating_add(1)
+            ));
+        }
+
+        Ok(length)
+    }
+
     /// Builds and returns the configured `hlssink3` instance.
     ///
     /// # Returns
     ///
     /// A `Result` containing the built `gst::Element` instance or an error if the element could not be created.
+    /// Fails if the configured DVR window is shorter than three segments, or if `max-files`
+    /// would delete segments the playlist still lists.
     pub fn build(self) -> Result<gst::Element> {
+        let length = self.resolved_playlist_length()?;
+        self.element.set_property("playlist-length", length);
+
         Ok(self.element)
     }
 }
@@ -180,6 +277,50 @@ mod tests {
         assert_eq!(builder.element.property::<u32>("playlist-length"), 20);
     }
 
+    #[test]
+    fn test_hlssink3_encoder_builder_with_dvr_seconds() {
+        init().unwrap();
+        let element = HlsSink3Builder::new("output/segment_%05d.ts", "output/playlist.m3u8")
+            .with_dvr_seconds(60)
+            .with_target_duration(6)
+            .build()
+            .unwrap();
+        assert_eq!(element.property::<u32>("playlist-length"), 10);
+    }
+
+    #[test]
+    fn test_hlssink3_encoder_builder_with_dvr_seconds_too_short() {
+        init().unwrap();
+        let result = HlsSink3Builder::new("output/segment_%05d.ts", "output/playlist.m3u8")
+            .with_target_duration(6)
+            .with_dvr_seconds(6)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hlssink3_encoder_builder_with_dvr_seconds_exceeding_max_files() {
+        init().unwrap();
+        let result = HlsSink3Builder::new("output/segment_%05d.ts", "output/playlist.m3u8")
+            .with_target_duration(6)
+            .with_max_files(10)
+            .with_dvr_seconds(120)
+            .build();
+        let err = result.err().expect("max-files below playlist-length should fail").to_string();
+        assert!(err.contains("max-files=10"), "unexpected error: {}", err);
+        assert!(err.contains("playlist-length=20"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_playlist_length_for_dvr() {
+        assert_eq!(playlist_length_for_dvr(6, 60).unwrap(), 10);
+        assert_eq!(playlist_length_for_dvr(6, 61).unwrap(), 11);
+        assert_eq!(playlist_length_for_dvr(6, 18).unwrap(), 3);
+        assert!(playlist_length_for_dvr(6, 6).is_err());
+        assert!(playlist_length_for_dvr(0, 60).is_err());
+        assert!(playlist_length_for_dvr(u32::MAX, 60).is_err());
+    }
+
     #[test]
     fn test_hlssink3_encoder_builder_with_max_files() {
         init().unwrap();
@@ -30,6 +30,11 @@ impl MpegTsMuxBuilder {
         Self { element }
     }
 
+    /// Returns the underlying `mpegtsmux` element without consuming the builder.
+    pub fn element(&self) -> &gst::Element {
+        &self.element
+    }
+
     /// Sets the `alignment` property of the `mpegtsmux` element.
     pub fn with_alignment(mut self, alignment: i32) -> Self {
         self.element.set_property("alignment", alignment);
@@ -120,6 +120,11 @@ impl NVH264EncBuilder {
         Self { element: element.element.clone() }
     }
 
+    /// Returns the underlying `nvh264enc` element without consuming the builder.
+    pub fn element(&self) -> &gst::Element {
+        &self.element
+    }
+
     /// Sets the `zerolatency` property of the `nvh264enc` element.
     pub fn with_zero_latency(mut self, zero_latency: bool) -> Self {
         self.element.set_property("zerolatency", zero_latency);
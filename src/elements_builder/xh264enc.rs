@@ -135,6 +135,11 @@ impl Xh264EncBuilder {
         builder
     }
 
+    /// Returns the underlying `x264enc` element without consuming the builder.
+    pub fn element(&self) -> &gst::Element {
+        &self.element
+    }
+
     /// Sets the `zerolatency` property of the `nvh264enc` element.
     pub fn with_zero_latency(mut self, zero_latency: bool) -> Self {
         self.element.set_property("zerolatency", zero_latency);
@@ -4,6 +4,7 @@ use crate::elements_builder::{
 use anyhow::{Context, Result};
 use gst::prelude::*;
 use gst::Element;
+use std::fmt;
 
 // Define an enum to encapsulate both encoder types
 pub enum H264Encoder {
@@ -27,6 +28,51 @@ impl H264Encoder {
             H264Encoder::X264(builder) => builder.build(),
         }
     }
+
+    /// Returns the encoder properties worth reporting in a configuration summary.
+    fn key_properties(&self) -> &'static [&'static str] {
+        match self {
+            H264Encoder::Nvenc(_) => &["bitrate", "gop-size", "bframes", "preset", "rc-mode"],
+            H264Encoder::X264(_) => &["bitrate", "key-int-max", "bframes", "speed-preset", "tune"],
+        }
+    }
+
+    /// Returns the encoder element held by the builder.
+    fn element(&self) -> &gst::Element {
+        match self {
+            H264Encoder::Nvenc(builder) => builder.element(),
+            H264Encoder::X264(builder) => builder.element(),
+        }
+    }
+}
+
+/// Returns the name of the factory an element was created from, falling back to its name.
+fn element_factory_name(element: &gst::Element) -> String {
+    element
+        .factory()
+        .map(|factory| factory.name().to_string())
+        .unwrap_or_else(|| element.name().to_string())
+}
+
+/// Formats the given properties of an element as `factory (name=value, ...)`.
+///
+/// Properties the element doesn't expose are skipped.
+fn summarize_element(factory_name: &str, element: &gst::Element, properties: &[&str]) -> String {
+    let settings = properties
+        .iter()
+        .filter(|name| element.find_property(name).is_some())
+        .map(|name| {
+            let value = element
+                .property_value(name)
+                .serialize()
+                .map(|value| value.to_string())
+                .unwrap_or_else(|_| "?".to_string());
+            format!("{}={}", name, value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{} ({})", factory_name, settings)
 }
 
 /// Represents a pipeline builder with configurations.
@@ -94,6 +140,40 @@ impl PipelineBuilder {
         }
     }
 
+    /// Sets the raw pixel format the video is converted to before reaching the encoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `format`: The raw video format (e.g., "I420", "NV12").
+    ///
+    /// The format is checked against the encoder's sink caps when the pipeline is built.
+    pub fn with_pixel_format(mut self, format: &str) -> Self {
+        self.capsfilter = self.capsfilter.with_format(format);
+        self
+    }
+
+    /// Sets the HLS playlist length from a DVR window expressed in seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `dvr_seconds`: The desired DVR window in seconds.
+    ///
+    /// The playlist length is derived from the sink's `target-duration` when the pipeline is
+    /// built, which fails if the window is shorter than three segments.
+    pub fn with_dvr_seconds(mut self, dvr_seconds: u32) -> Self {
+        self.hlssink = self.hlssink.with_dvr_seconds(dvr_seconds);
+        self
+    }
+
+    /// Returns a human-readable summary of the resolved pipeline configuration.
+    ///
+    /// Covers the input, output paths, resolution, framerate, encoder, muxer and sink settings,
+    /// so it can be pasted into bug reports. Unlike a DOT export, this describes the
+    /// configuration rather than the pipeline graph.
+    pub fn describe(&self) -> String {
+        self.to_string()
+    }
+
     fn create_queue(name: &str) -> Result<Element> {
         gst::ElementFactory::make_with_name("queue", Some(name)).map_err(|err| err.into())
     }
@@ -103,6 +183,36 @@ impl PipelineBuilder {
             .map_err(|err| err.into())
     }
 
+    /// Ensures the raw caps configured on the `capsfilter` can be accepted by the encoder.
+    ///
+    /// Intersects the `caps` property of the `capsfilter` with the encoder's `sink` pad template
+    /// caps, returning an error naming the incompatible format when they don't intersect.
+    fn validate_caps_compatibility(caps_filter: &Element, encoder: &Element) -> Result<()> {
+        let raw_caps = caps_filter.property::<gst::Caps>("caps");
+        let encoder_name = element_factory_name(encoder);
+
+        let sink_caps = encoder
+            .pad_template("sink")
+            .map(|template| template.caps().clone())
+            .ok_or_else(|| anyhow::anyhow!("Encoder {} has no sink pad template", encoder_name))?;
+
+        if raw_caps.can_intersect(&sink_caps) {
+            return Ok(());
+        }
+
+        let format = raw_caps
+            .structure(0)
+            .and_then(|structure| structure.get::<String>("format").ok())
+            .unwrap_or_else(|| raw_caps.to_string());
+
+        Err(anyhow::anyhow!(
+            "Incompatible raw format: {} is not accepted by encoder {} (sink caps: {})",
+            format,
+            encoder_name,
+            sink_caps
+        ))
+    }
+
     /// Builds and configures the GStreamer pipeline for both video and audio processing.
     pub fn build(self) -> Result<gst::Pipeline> {
         let pipeline_name = format!("pipeline_{}", self.variant_name);
@@ -125,6 +235,9 @@ impl PipelineBuilder {
         let video_scaler =
             Self::create_element("videoscale").context("Failed to create video scaler element")?;
 
+        let video_converter = Self::create_element("videoconvert")
+            .context("Failed to create video converter element")?;
+
         let video_caps_filter = self
             .capsfilter
             .build()
@@ -136,6 +249,8 @@ impl PipelineBuilder {
             .build()
             .context("Failed to create video encoder element")?;
 
+        Self::validate_caps_compatibility(&video_caps_filter, &video_encoder)?;
+
         let h264_parser =
             Self::create_element("h264parse").context("Failed to create h264parse element")?;
 
@@ -166,6 +281,7 @@ impl PipelineBuilder {
             &decode_bin,
             &video_queue,
             &video_scaler,
+            &video_converter,
             &video_caps_filter,
             &video_encoder,
             &h264_parser,
@@ -204,6 +320,7 @@ impl PipelineBuilder {
                 gst::Element::link_many(&[
                     &video_queue,
                     &video_scaler,
+                    &video_converter,
                     &video_caps_filter,
                     &video_encoder,
                     &h264_parser,
@@ -240,6 +357,84 @@ impl PipelineBuilder {
     }
 }
 
+impl fmt::Display for PipelineBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw_caps = self.capsfilter.caps().structure(0);
+        let caps_field = |name: &str| {
+            raw_caps
+                .and_then(|structure| structure.value(name).ok())
+                .and_then(|value| value.serialize().ok())
+                .map(|value| value.to_string())
+        };
+        let resolution = match (caps_field("width"), caps_field("height")) {
+            (Some(width), Some(height)) => format!("{}x{}", width, height),
+            _ => "source".to_string(),
+        };
+
+        let encoder_element = self.video_encoder.element();
+        let encoder = summarize_element(
+            &element_factory_name(encoder_element),
+            encoder_element,
+            self.video_encoder.key_properties(),
+        );
+
+        let muxer_element = self.mpegtsmux.element();
+        let muxer = summarize_element(
+            &element_factory_name(muxer_element),
+            muxer_element,
+            &["alignment", "pat-interval", "pmt-interval", "pcr-interval", "m2ts-mode"],
+        );
+
+        let sink_element = self.hlssink.element();
+        let sink = summarize_element(
+            &element_factory_name(sink_element),
+            sink_element,
+            &["target-duration", "max-files", "playlist-type"],
+        );
+        let playlist_length = self
+            .hlssink
+            .resolved_playlist_length()
+            .map(|length| length.to_string())
+            .unwrap_or_else(|err| format!("invalid ({})", err));
+        let dvr_window = self
+            .hlssink
+            .dvr_seconds()
+            .map(|seconds| format!("{}s", seconds))
+            .unwrap_or_else(|| "none".to_string());
+
+        writeln!(f, "Pipeline: pipeline_{}", self.variant_name)?;
+        writeln!(f, "  Variant:      {}", self.variant_name)?;
+        writeln!(f, "  Input:        {}", self.input_file)?;
+        writeln!(
+            f,
+            "  Playlist:     {}",
+            sink_element.property::<String>("playlist-location")
+        )?;
+        writeln!(f, "  Segments:     {}", sink_element.property::<String>("location"))?;
+        writeln!(f, "  Resolution:   {}", resolution)?;
+        writeln!(
+            f,
+            "  Framerate:    {}",
+            caps_field("framerate").unwrap_or_else(|| "source".to_string())
+        )?;
+        writeln!(
+            f,
+            "  Pixel format: {}",
+            caps_field("format").unwrap_or_else(|| "source".to_string())
+        )?;
+        writeln!(
+            f,
+            "  Acceleration: {}",
+            if self.nvh { "nvenc" } else { "software" }
+        )?;
+        writeln!(f, "  Encoder:      {}", encoder)?;
+        writeln!(f, "  Muxer:        {}", muxer)?;
+        writeln!(f, "  Sink:         {}", sink)?;
+        writeln!(f, "  Playlist len: {}", playlist_length)?;
+        write!(f, "  DVR window:   {}", dvr_window)
+    }
+}
+
 /// Test suite for HLS pipeline construction and processing.
 #[cfg(test)]
 mod tests {
@@ -293,6 +488,72 @@ mod tests {
         assert!(segment_files.len() > 0, "No HLS segments were created");
     }
 
+    /// Test that a YUV raw format accepted by NVENC builds the pipeline.
+    #[test]
+    fn test_compatible_pixel_format_for_nvenc() {
+        init().unwrap();
+
+        let pipeline = create_pipeline_builder().with_pixel_format("NV12").build().unwrap();
+
+        assert!(pipeline.by_name("videoconvert").is_some());
+        assert_eq!(
+            pipeline
+                .by_name("capsfilter")
+                .unwrap()
+                .property::<gst::Caps>("caps")
+                .structure(0)
+                .unwrap()
+                .get::<String>("format")
+                .unwrap(),
+            "NV12"
+        );
+    }
+
+    /// Test that an RGB raw format is rejected up front when routed into NVENC.
+    #[test]
+    fn test_incompatible_pixel_format_for_nvenc() {
+        init().unwrap();
+
+        let result = create_pipeline_builder().with_pixel_format("RGB").build();
+
+        let err = result.err().expect("RGB should be rejected by nvh264enc").to_string();
+        assert!(err.contains("Incompatible raw format"), "unexpected error: {}", err);
+        assert!(err.contains("RGB"), "error should name the format: {}", err);
+        assert!(err.contains("nvh264enc"), "error should name the encoder: {}", err);
+    }
+
+    /// Test that the configuration summary reports the variant, resolution and encoder.
+    #[test]
+    fn test_pipeline_describe() {
+        init().unwrap();
+
+        let builder = create_pipeline_builder();
+        let description = builder.describe();
+
+        assert!(description.contains(PIPELINE_NAME), "missing variant: {}", description);
+        assert!(description.contains("1280x720"), "missing resolution: {}", description);
+        assert!(description.contains("nvh264enc"), "missing encoder: {}", description);
+        assert!(
+            description.contains(&format!("{}/{}/playlist.m3u8", VARIANT_NAME, PIPELINE_NAME)),
+            "missing playlist path: {}",
+            description
+        );
+        assert!(description.contains("pat-interval=2000"), "missing PAT interval: {}", description);
+        assert!(description.contains("pcr-interval=40"), "missing PCR interval: {}", description);
+    }
+
+    /// Test that the summary reports the playlist length resolved from a DVR window.
+    #[test]
+    fn test_pipeline_describe_with_dvr_seconds() {
+        init().unwrap();
+
+        let description = create_pipeline_builder().with_dvr_seconds(60).describe();
+
+        assert!(description.contains("target-duration=5"), "missing target: {}", description);
+        assert!(description.contains("Playlist len: 12"), "unresolved length: {}", description);
+        assert!(description.contains("DVR window:   60s"), "missing DVR window: {}", description);
+    }
+
     /// Test to validate state transitions of the pipeline.
     #[test]
     fn test_pipeline_state_transitions() {